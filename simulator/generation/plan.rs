@@ -0,0 +1,224 @@
+use limbo_core::LimboError;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    generation::property::Property,
+    model::query::Query,
+    runner::env::SimulatorEnv,
+};
+
+/// The rows returned by a query, or the error it failed with.
+pub(crate) type ResultSet = Result<Vec<Vec<crate::model::table::Value>>, LimboError>;
+
+/// A boolean-valued check run against the interaction stack and the current
+/// environment. Used both for `Interaction::Assumption` (skip the property if
+/// false) and `Interaction::Assertion` (fail the property if false).
+pub(crate) struct Assertion {
+    pub(crate) message: String,
+    pub(crate) func: Box<dyn Fn(&Vec<ResultSet>, &SimulatorEnv) -> Result<bool, LimboError>>,
+    /// Optional expected-vs-actual description, computed lazily only when
+    /// `func` returns `Ok(false)`, so that a `VerifyFailure` report can show
+    /// more than just the failure message. The first element of the pair is
+    /// the expected description, the second the actual one.
+    pub(crate) diagnose: Option<Box<dyn Fn(&Vec<ResultSet>, &SimulatorEnv) -> (String, String)>>,
+}
+
+impl Assertion {
+    /// Build an `Assertion` with no expected-vs-actual diagnosis, for the
+    /// common case (e.g. every `Interaction::Assumption`, and assertions
+    /// whose `message` already says everything worth saying).
+    pub(crate) fn new(
+        message: String,
+        func: Box<dyn Fn(&Vec<ResultSet>, &SimulatorEnv) -> Result<bool, LimboError>>,
+    ) -> Self {
+        Self {
+            message,
+            func,
+            diagnose: None,
+        }
+    }
+
+    /// Attach an expected-vs-actual diagnosis, for reporting on failure.
+    pub(crate) fn with_diagnose(
+        mut self,
+        diagnose: Box<dyn Fn(&Vec<ResultSet>, &SimulatorEnv) -> (String, String)>,
+    ) -> Self {
+        self.diagnose = Some(diagnose);
+        self
+    }
+}
+
+/// A single step of a generated plan: either a query to execute against
+/// limbo_core, an assumption that must hold for the rest of the property to
+/// make sense, or an assertion that must hold for the property to pass.
+pub(crate) enum Interaction {
+    Query(Query),
+    Assumption(Assertion),
+    Assertion(Assertion),
+}
+
+/// Where in a property's plan a failure occurred: which property, and the
+/// zero-based index of the failing interaction within the `Vec<Interaction>`
+/// returned by `Property::interactions()`.
+pub(crate) struct FailureLocation {
+    pub(crate) property: String,
+    pub(crate) interaction_index: usize,
+    pub(crate) interaction_role: String,
+    /// The concrete SQL of the query interaction immediately preceding the
+    /// failing assertion, if any (an assertion is not required to be
+    /// preceded by a query, though in practice every one built in
+    /// `property.rs` is).
+    pub(crate) query: Option<String>,
+}
+
+/// A structured report of a failed `Assertion` or `Assumption`, ported from
+/// the `FailureLocation`/`VerifyFailure` idea in halo2's dev tools: enough
+/// context to point at exactly what went wrong without having to re-run the
+/// plan under a debugger.
+///
+/// NOT CURRENTLY CONSTRUCTED: this crate has no interaction runner (nothing
+/// executes a `Property`'s `Vec<Interaction>` against `limbo_core`, so
+/// nothing calls `verify_failure` below either). Build this from that
+/// runner, once one exists in this crate, at the point where an `Assertion`
+/// or `Assumption` returns `Ok(false)`.
+pub(crate) struct VerifyFailure {
+    pub(crate) location: FailureLocation,
+    pub(crate) message: String,
+    /// The messages of every `Interaction::Assumption` that held up to (and
+    /// not including) the failing interaction.
+    pub(crate) assumption_trail: Vec<String>,
+    pub(crate) expected: Option<String>,
+    pub(crate) actual: Option<String>,
+}
+
+/// Build a `VerifyFailure` report for the interaction at `failing_index`
+/// within `interactions` (as returned by `property.interactions()`).
+pub(crate) fn verify_failure(
+    property: &Property,
+    interactions: &[Interaction],
+    failing_index: usize,
+    assertion: &Assertion,
+    stack: &Vec<ResultSet>,
+    env: &SimulatorEnv,
+) -> VerifyFailure {
+    let query = interactions[..=failing_index].iter().rev().find_map(|i| match i {
+        Interaction::Query(q) => Some(q.to_string()),
+        _ => None,
+    });
+
+    let assumption_trail = interactions[..failing_index]
+        .iter()
+        .filter_map(|i| match i {
+            Interaction::Assumption(a) => Some(a.message.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let (expected, actual) = match &assertion.diagnose {
+        Some(diagnose) => {
+            let (expected, actual) = diagnose(stack, env);
+            (Some(expected), Some(actual))
+        }
+        None => (None, None),
+    };
+
+    let roles = property.interaction_roles();
+    let interaction_role = roles
+        .get(failing_index)
+        .cloned()
+        .unwrap_or_else(|| "<unknown interaction>".to_string());
+
+    VerifyFailure {
+        location: FailureLocation {
+            property: property.name(),
+            interaction_index: failing_index,
+            interaction_role,
+            query,
+        },
+        message: assertion.message.clone(),
+        assumption_trail,
+        expected,
+        actual,
+    }
+}
+
+/// How a single generated shape (a `Property::name()`, or a query shape like
+/// `"insert"`) fared when it was actually run.
+pub(crate) enum Outcome {
+    Passed,
+    /// Its leading `Interaction::Assumption` returned false, so the rest of
+    /// the plan was never exercised.
+    AssumptionSkipped,
+    Failed,
+}
+
+/// Coverage counters for a single shape, accumulated across a run (and, via
+/// `InteractionStats`'s `Serialize`/`Deserialize`, across a replay of the
+/// same seed). Used by `GenerationStrategy::CoverageGuided` to bias `Property`
+/// and `Query` generation towards shapes that are under-exercised or
+/// historically discrepancy-prone.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub(crate) struct ShapeCoverage {
+    pub(crate) passed: usize,
+    pub(crate) assumption_skipped: usize,
+    pub(crate) failed: usize,
+}
+
+impl ShapeCoverage {
+    pub(crate) fn total(&self) -> usize {
+        self.passed + self.assumption_skipped + self.failed
+    }
+}
+
+/// Running counters of what has been generated and executed so far, used to
+/// bias generation towards the configured read/write/create budget.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub(crate) struct InteractionStats {
+    pub(crate) read_count: usize,
+    pub(crate) write_count: usize,
+    pub(crate) create_count: usize,
+    pub(crate) transaction_count: usize,
+    /// Per-shape coverage, keyed by `Property::name()` or by query shape
+    /// (`"insert"`, `"delete"`, ...). Persisted alongside the rest of these
+    /// stats so that replaying the same seed reproduces the same
+    /// coverage-guided schedule rather than starting from a blank slate.
+    pub(crate) shape_coverage: std::collections::HashMap<String, ShapeCoverage>,
+}
+
+impl InteractionStats {
+    // NOT CURRENTLY CALLED: this crate has no interaction runner (nothing
+    // executes a `Property`'s `Vec<Interaction>` against `limbo_core` and
+    // feeds the outcome back here), so `shape_coverage` stays empty and
+    // `coverage_factor` below always returns `1.0`. Call this from that
+    // runner, once one exists in this crate, after each interaction
+    // completes.
+    pub(crate) fn record_outcome(&mut self, shape: &str, outcome: Outcome) {
+        let coverage = self.shape_coverage.entry(shape.to_string()).or_default();
+        match outcome {
+            Outcome::Passed => coverage.passed += 1,
+            Outcome::AssumptionSkipped => coverage.assumption_skipped += 1,
+            Outcome::Failed => coverage.failed += 1,
+        }
+    }
+}
+
+/// How much a shape's base (budget-derived) generation weight should be
+/// multiplied by, given its coverage so far. The fewer times a shape has
+/// run, the larger its factor; shapes that have surfaced failures are
+/// up-weighted further; shapes that mostly skip via their own assumption are
+/// down-weighted, since spending budget generating them is mostly wasted.
+pub(crate) fn coverage_factor(stats: &InteractionStats, shape: &str) -> f64 {
+    let coverage = stats.shape_coverage.get(shape);
+    let total = coverage.map(ShapeCoverage::total).unwrap_or(0);
+    let failed = coverage.map(|c| c.failed).unwrap_or(0);
+    let skipped = coverage.map(|c| c.assumption_skipped).unwrap_or(0);
+
+    // +1 avoids dividing by zero and keeps a never-seen shape from
+    // dominating every other shape's weight outright.
+    let under_exercised = 1.0 / (total as f64 + 1.0);
+    let discrepancy_prone = 1.0 + failed as f64;
+    let skip_ratio = if total > 0 { skipped as f64 / total as f64 } else { 0.0 };
+    let assumption_penalty = (1.0 - skip_ratio).max(0.1);
+
+    under_exercised * discrepancy_prone * assumption_penalty
+}
@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     model::{
-        query::{Create, Delete, Insert, Predicate, Query, Select},
+        query::{
+            AlterAddColumn, AlterRenameTable, Create, Delete, Drop, Insert, Predicate, Query,
+            Select, Update,
+        },
         table::Value,
     },
     runner::env::SimulatorEnv,
@@ -11,10 +14,29 @@ use crate::{
 
 use super::{
     frequency, pick, pick_index,
-    plan::{Assertion, Interaction, InteractionStats, ResultSet},
-    ArbitraryFrom,
+    plan::{coverage_factor, Assertion, Interaction, InteractionStats, ResultSet},
+    ArbitraryFrom, GenerationStrategy,
 };
 
+/// Compare two result sets as unordered multisets of rows, using `Value`'s
+/// own row-identity equality (where `NULL == NULL`, unlike the SQL `=` used
+/// by `Predicate::test`). Row order is not compared.
+fn rows_match_unordered(a: &[Vec<Value>], b: &[Vec<Value>]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut unmatched = b.iter().collect::<Vec<_>>();
+    for row in a {
+        match unmatched.iter().position(|r| *r == row) {
+            Some(idx) => {
+                unmatched.swap_remove(idx);
+            }
+            None => return false,
+        }
+    }
+    true
+}
+
 /// Properties are representations of executable specifications
 /// about the database behavior.
 #[derive(Clone, Serialize, Deserialize)]
@@ -62,6 +84,119 @@ pub(crate) enum Property {
         /// Additional interactions in the middle of the property
         queries: Vec<Query>,
     },
+    /// Uncommitted Rollback is a property that exercises transaction and
+    /// savepoint atomicity: a batch of writes is applied inside a scope that
+    /// is then rolled back, and the table must be left exactly as it was
+    /// before the scope was entered.
+    /// The non-nested execution of the property is as follows
+    ///     SELECT * FROM <t>           -- snapshot
+    ///     BEGIN
+    ///     I_0
+    ///     I_1
+    ///     ...
+    ///     I_n
+    ///     ROLLBACK
+    ///     SELECT * FROM <t>           -- must equal the snapshot
+    /// When `nested` is set, an additional `surviving_insert` is applied
+    /// right after `BEGIN` and before a `SAVEPOINT` is taken, so the
+    /// execution becomes
+    ///     SELECT * FROM <t>           -- snapshot
+    ///     BEGIN
+    ///     INSERT INTO <t> VALUES (...)  -- surviving_insert
+    ///     SAVEPOINT sp1
+    ///     I_0
+    ///     ...
+    ///     I_n
+    ///     ROLLBACK TO sp1
+    ///     RELEASE sp1                -- the enclosing transaction stays open
+    ///     SELECT * FROM <t>           -- must equal snapshot + surviving_insert
+    /// The interactions in the middle has the following constraints;
+    /// - There will be no errors in the middle interactions.
+    UncommittedRollback {
+        /// The table being mutated
+        table: String,
+        /// An insert applied before entering the rolled-back scope, which
+        /// must survive a nested `SAVEPOINT` rollback. `None` for the
+        /// non-nested, whole-transaction case.
+        surviving_insert: Option<Insert>,
+        /// The queries that are applied inside the rolled-back scope and
+        /// must be fully discarded.
+        queries: Vec<Query>,
+        /// Whether the rolled-back scope is a nested `SAVEPOINT` rather than
+        /// the enclosing transaction itself.
+        nested: bool,
+    },
+    /// Select-Matches-Model checks a `SELECT` against the row-level shadow
+    /// oracle tracked in `SimulatorEnv`, rather than against a single
+    /// previously-inserted row like `InsertSelect` does. This is the general
+    /// case: any table, any predicate, checked against whatever the oracle
+    /// believes the table's contents to be.
+    /// The execution of the property is as follows
+    ///     SELECT * FROM <t> WHERE <predicate>
+    /// The assertion compares the real result set against the oracle's rows
+    /// filtered by the same predicate, as an unordered multiset. If the
+    /// oracle is poisoned for `t` (a middle interaction touching it errored
+    /// at some point) the assumption fails and the property is skipped
+    /// rather than risking a false positive.
+    SelectMatchesModel {
+        /// The table being selected from
+        table: String,
+        /// The select predicate, also used to filter the oracle's rows
+        predicate: Predicate,
+    },
+    /// Update-Select is a property in which a row is inserted, updated, and
+    /// must then show up under its *new* values in a select, while its old
+    /// values must no longer be found.
+    /// The execution of the property is as follows
+    ///     INSERT INTO <t> VALUES (...)
+    ///     UPDATE <t> SET ... WHERE <predicate matching the inserted row>
+    ///     SELECT * FROM <t>
+    UpdateSelect {
+        /// The insert query
+        insert: Insert,
+        /// Selected row index within `insert.values`
+        row_index: usize,
+        /// The update query, whose predicate targets the inserted row
+        update: Update,
+        /// The inserted row's values after `update`'s assignments are applied
+        updated_row: Vec<Value>,
+        /// The select query
+        select: Select,
+    },
+    /// Drop Ensures Absence is a property in which dropping a table makes it
+    /// permanently unselectable, mirroring the error-string assertion
+    /// pattern used in `DoubleCreateFailure`.
+    /// The execution of the property is as follows
+    ///     DROP TABLE <t>
+    ///     I_0
+    ///     I_1
+    ///     ...
+    ///     I_n
+    ///     SELECT * FROM <t> -> Error
+    /// The interactions in the middle has the following constraints;
+    /// - There will be no errors in the middle interactions: they target a
+    ///   surviving table other than `t`, never `t` itself, since `t` no
+    ///   longer exists once DROP has run.
+    /// - Table `t` will not be recreated.
+    DropEnsuresAbsence {
+        /// The table being dropped
+        table: String,
+        /// Additional interactions in the middle of the property
+        queries: Vec<Query>,
+    },
+    /// Rename Then Select is a property in which renaming a table preserves
+    /// its rows under the new name.
+    /// The execution of the property is as follows
+    ///     INSERT INTO <t> VALUES (...)
+    ///     ALTER TABLE <t> RENAME TO <new_name>
+    ///     SELECT * FROM <new_name>
+    /// The select must return every row that was inserted into `t`.
+    RenameThenSelect {
+        /// The insert query, executed against the table's original name
+        insert: Insert,
+        /// The table's name after the rename
+        new_name: String,
+    },
 }
 
 impl Property {
@@ -69,6 +204,14 @@ impl Property {
         match self {
             Property::InsertSelect { .. } => "Insert-Select".to_string(),
             Property::DoubleCreateFailure { .. } => "Double-Create-Failure".to_string(),
+            Property::UncommittedRollback { nested: false, .. } => "Uncommitted-Rollback".to_string(),
+            Property::UncommittedRollback { nested: true, .. } => {
+                "Nested-Savepoint-Rollback".to_string()
+            }
+            Property::SelectMatchesModel { .. } => "Select-Matches-Model".to_string(),
+            Property::UpdateSelect { .. } => "Update-Select".to_string(),
+            Property::DropEnsuresAbsence { .. } => "Drop-Ensures-Absence".to_string(),
+            Property::RenameThenSelect { .. } => "Rename-Then-Select".to_string(),
         }
     }
     /// interactions construct a list of interactions, which is an executable representation of the property.
@@ -92,30 +235,45 @@ impl Property {
                 let row = insert.values[*row_index].clone();
 
                 // Assume that the table exists
-                let assumption = Interaction::Assumption(Assertion {
-                    message: format!("table {} exists", insert.table),
-                    func: Box::new({
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {} exists", insert.table),
+                    Box::new({
                         let table_name = insert.table.clone();
                         move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
                             Ok(env.tables.iter().any(|t| t.name == table_name))
                         }
                     }),
-                });
+                ));
 
-                let assertion = Interaction::Assertion(Assertion {
-                    message: format!(
-                        "row [{:?}] not found in table {}",
-                        row.iter().map(|v| v.to_string()).collect::<Vec<String>>(),
-                        insert.table,
-                    ),
-                    func: Box::new(move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
-                        let rows = stack.last().unwrap();
-                        match rows {
-                            Ok(rows) => Ok(rows.iter().any(|r| r == &row)),
-                            Err(err) => Err(LimboError::InternalError(err.to_string())),
+                let assertion = Interaction::Assertion(
+                    Assertion::new(
+                        format!(
+                            "row [{:?}] not found in table {}",
+                            row.iter().map(|v| v.to_string()).collect::<Vec<String>>(),
+                            insert.table,
+                        ),
+                        Box::new({
+                            let row = row.clone();
+                            move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                                let rows = stack.last().unwrap();
+                                match rows {
+                                    Ok(rows) => Ok(rows.iter().any(|r| r == &row)),
+                                    Err(err) => Err(LimboError::InternalError(err.to_string())),
+                                }
+                            }
+                        }),
+                    )
+                    .with_diagnose(Box::new({
+                        let row = row.clone();
+                        move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                            let actual = match stack.last().unwrap() {
+                                Ok(rows) => format!("{rows:?}"),
+                                Err(e) => format!("error: {e}"),
+                            };
+                            (format!("row {row:?} present"), actual)
                         }
-                    }),
-                });
+                    })),
+                );
 
                 let mut interactions = Vec::new();
                 interactions.push(assumption);
@@ -129,31 +287,30 @@ impl Property {
             Property::DoubleCreateFailure { create, queries } => {
                 let table_name = create.table.name.clone();
 
-                let assumption = Interaction::Assumption(Assertion {
-                    message: "Double-Create-Failure should not be called on an existing table"
+                let assumption = Interaction::Assumption(Assertion::new(
+                    "Double-Create-Failure should not be called on an existing table"
                         .to_string(),
-                    func: Box::new(move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                    Box::new(move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
                         Ok(!env.tables.iter().any(|t| t.name == table_name))
                     }),
-                });
+                ));
 
                 let cq1 = Interaction::Query(Query::Create(create.clone()));
                 let cq2 = Interaction::Query(Query::Create(create.clone()));
 
                 let table_name = create.table.name.clone();
 
-                let assertion = Interaction::Assertion(Assertion {
-                    message:
-                        "creating two tables with the name should result in a failure for the second query"
-                            .to_string(),
-                    func: Box::new(move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                let assertion = Interaction::Assertion(Assertion::new(
+                    "creating two tables with the name should result in a failure for the second query"
+                        .to_string(),
+                    Box::new(move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
                         let last = stack.last().unwrap();
                         match last {
                             Ok(_) => Ok(false),
                             Err(e) => Ok(e.to_string().contains(&format!("Table {table_name} already exists"))),
                         }
                     }),
-                });
+                ));
 
                 let mut interactions = Vec::new();
                 interactions.push(assumption);
@@ -164,7 +321,438 @@ impl Property {
 
                 interactions
             }
+            Property::UncommittedRollback {
+                table,
+                surviving_insert,
+                queries,
+                nested,
+            } => {
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {table} exists"),
+                    Box::new({
+                        let table_name = table.clone();
+                        move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                            Ok(env.tables.iter().any(|t| t.name == table_name))
+                        }
+                    }),
+                ));
+
+                let snapshot_select = Interaction::Query(Query::Select(Select {
+                    table: table.clone(),
+                    predicate: Predicate::True,
+                }));
+
+                let post_select = Interaction::Query(Query::Select(Select {
+                    table: table.clone(),
+                    predicate: Predicate::True,
+                }));
+
+                // Number of queries pushed onto the result stack between the
+                // snapshot select (exclusive) and the post select (inclusive):
+                // BEGIN, the optional surviving insert, the optional
+                // SAVEPOINT, the rolled-back batch, and finally ROLLBACK [TO]
+                // (+ RELEASE for the nested case) and the post select itself.
+                let surviving_rows = surviving_insert
+                    .as_ref()
+                    .map(|insert| insert.values.clone())
+                    .unwrap_or_default();
+                let between = 1 // BEGIN
+                    + surviving_insert.is_some() as usize // surviving insert
+                    + *nested as usize // SAVEPOINT
+                    + queries.len()
+                    + 1 // ROLLBACK / ROLLBACK TO
+                    + *nested as usize // RELEASE, only issued in the nested case
+                    + 1; // the post select itself
+
+                let assertion = Interaction::Assertion(
+                    Assertion::new(
+                        format!(
+                            "table {table} should equal its pre-transaction contents{}",
+                            if *nested { " plus the surviving insert" } else { "" }
+                        ),
+                        Box::new({
+                            let surviving_rows = surviving_rows.clone();
+                            move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                                let snapshot_idx = stack.len().saturating_sub(between + 1);
+                                let snapshot = stack[snapshot_idx].as_ref().map_err(|e| {
+                                    LimboError::InternalError(e.to_string())
+                                })?;
+                                let after = stack.last().unwrap().as_ref().map_err(|e| {
+                                    LimboError::InternalError(e.to_string())
+                                })?;
+
+                                let mut expected = snapshot.clone();
+                                expected.extend(surviving_rows.clone());
+
+                                Ok(rows_match_unordered(&expected, after))
+                            }
+                        }),
+                    )
+                    .with_diagnose(Box::new({
+                        let surviving_rows = surviving_rows.clone();
+                        move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                            let snapshot_idx = stack.len().saturating_sub(between + 1);
+                            let mut expected = stack
+                                .get(snapshot_idx)
+                                .and_then(|r| r.as_ref().ok())
+                                .cloned()
+                                .unwrap_or_default();
+                            expected.extend(surviving_rows.clone());
+                            let actual = match stack.last().unwrap() {
+                                Ok(rows) => format!("{rows:?}"),
+                                Err(e) => format!("error: {e}"),
+                            };
+                            (format!("{expected:?}"), actual)
+                        }
+                    })),
+                );
+
+                let mut interactions = Vec::new();
+                interactions.push(assumption);
+                interactions.push(snapshot_select);
+                interactions.push(Interaction::Query(Query::Begin));
+                if let Some(insert) = surviving_insert {
+                    interactions.push(Interaction::Query(Query::Insert(insert.clone())));
+                }
+                if *nested {
+                    interactions.push(Interaction::Query(Query::Savepoint("sp1".to_string())));
+                }
+                interactions.extend(queries.clone().into_iter().map(Interaction::Query));
+                if *nested {
+                    interactions.push(Interaction::Query(Query::RollbackTo("sp1".to_string())));
+                    interactions.push(Interaction::Query(Query::ReleaseSavepoint(
+                        "sp1".to_string(),
+                    )));
+                } else {
+                    interactions.push(Interaction::Query(Query::Rollback));
+                }
+                interactions.push(post_select);
+                interactions.push(assertion);
+
+                interactions
+            }
+            Property::SelectMatchesModel { table, predicate } => {
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {table} exists and its oracle is not poisoned"),
+                    Box::new({
+                        let table_name = table.clone();
+                        move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                            Ok(env.tables.iter().any(|t| t.name == table_name)
+                                && env
+                                    .oracle
+                                    .get(&table_name)
+                                    .map(|o| !o.poisoned)
+                                    .unwrap_or(false))
+                        }
+                    }),
+                ));
+
+                let select = Interaction::Query(Query::Select(Select {
+                    table: table.clone(),
+                    predicate: predicate.clone(),
+                }));
+
+                let expected_rows = {
+                    let table_name = table.clone();
+                    let predicate = predicate.clone();
+                    move |env: &SimulatorEnv| -> Vec<Vec<Value>> {
+                        let table_schema = env
+                            .tables
+                            .iter()
+                            .find(|t| t.name == table_name)
+                            .expect("assumption guarantees the table exists");
+                        let oracle = env
+                            .oracle
+                            .get(&table_name)
+                            .expect("assumption guarantees the oracle is present");
+                        oracle
+                            .rows
+                            .iter()
+                            .filter(|row| predicate.test(row, table_schema))
+                            .cloned()
+                            .collect()
+                    }
+                };
+
+                let assertion = Interaction::Assertion(
+                    Assertion::new(
+                        format!(
+                            "select over {table} does not match the shadow oracle's rows for {predicate}"
+                        ),
+                        Box::new({
+                            let expected_rows = expected_rows.clone();
+                            move |stack: &Vec<ResultSet>, env: &SimulatorEnv| {
+                                let expected = expected_rows(env);
+                                let actual = stack
+                                    .last()
+                                    .unwrap()
+                                    .as_ref()
+                                    .map_err(|e| LimboError::InternalError(e.to_string()))?;
+
+                                Ok(rows_match_unordered(&expected, actual))
+                            }
+                        }),
+                    )
+                    .with_diagnose(Box::new(move |stack: &Vec<ResultSet>, env: &SimulatorEnv| {
+                        let expected = expected_rows(env);
+                        let actual = match stack.last().unwrap() {
+                            Ok(rows) => format!("{rows:?}"),
+                            Err(e) => format!("error: {e}"),
+                        };
+                        (format!("{expected:?}"), actual)
+                    })),
+                );
+
+                vec![assumption, select, assertion]
+            }
+            Property::UpdateSelect {
+                insert,
+                row_index,
+                update,
+                updated_row,
+                select,
+            } => {
+                assert!(
+                    !insert.values.is_empty(),
+                    "insert query should have at least 1 value"
+                );
+
+                let old_row = insert.values[*row_index].clone();
+
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {} exists", insert.table),
+                    Box::new({
+                        let table_name = insert.table.clone();
+                        move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                            Ok(env.tables.iter().any(|t| t.name == table_name))
+                        }
+                    }),
+                ));
+
+                let assertion = Interaction::Assertion(
+                    Assertion::new(
+                        format!(
+                            "updated row [{:?}] not found (or old row [{:?}] still present) in table {}",
+                            updated_row.iter().map(|v| v.to_string()).collect::<Vec<String>>(),
+                            old_row.iter().map(|v| v.to_string()).collect::<Vec<String>>(),
+                            insert.table,
+                        ),
+                        Box::new({
+                            let updated_row = updated_row.clone();
+                            let old_row = old_row.clone();
+                            move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                                let rows = stack
+                                    .last()
+                                    .unwrap()
+                                    .as_ref()
+                                    .map_err(|e| LimboError::InternalError(e.to_string()))?;
+                                Ok(rows.iter().any(|r| r == &updated_row) && !rows.iter().any(|r| r == &old_row))
+                            }
+                        }),
+                    )
+                    .with_diagnose(Box::new({
+                        let updated_row = updated_row.clone();
+                        move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                            let actual = match stack.last().unwrap() {
+                                Ok(rows) => format!("{rows:?}"),
+                                Err(e) => format!("error: {e}"),
+                            };
+                            (format!("{updated_row:?} present, old row absent"), actual)
+                        }
+                    })),
+                );
+
+                vec![
+                    assumption,
+                    Interaction::Query(Query::Insert(insert.clone())),
+                    Interaction::Query(Query::Update(update.clone())),
+                    Interaction::Query(Query::Select(select.clone())),
+                    assertion,
+                ]
+            }
+            Property::DropEnsuresAbsence { table, queries } => {
+                let table_name = table.clone();
+
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {table} exists"),
+                    Box::new(move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                        Ok(env.tables.iter().any(|t| t.name == table_name))
+                    }),
+                ));
+
+                let drop_query = Interaction::Query(Query::Drop(Drop {
+                    table: table.clone(),
+                }));
+
+                let select_query = Interaction::Query(Query::Select(Select {
+                    table: table.clone(),
+                    predicate: Predicate::True,
+                }));
+
+                let table_name = table.clone();
+                let assertion = Interaction::Assertion(Assertion::new(
+                    format!("select over dropped table {table} should error with \"no such table\""),
+                    Box::new(move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                        let last = stack.last().unwrap();
+                        match last {
+                            Ok(_) => Ok(false),
+                            Err(e) => Ok(e.to_string().contains(&format!("no such table: {table_name}"))),
+                        }
+                    }),
+                ));
+
+                let mut interactions = Vec::new();
+                interactions.push(assumption);
+                interactions.push(drop_query);
+                interactions.extend(queries.clone().into_iter().map(Interaction::Query));
+                interactions.push(select_query);
+                interactions.push(assertion);
+
+                interactions
+            }
+            Property::RenameThenSelect { insert, new_name } => {
+                assert!(
+                    !insert.values.is_empty(),
+                    "insert query should have at least 1 value"
+                );
+
+                let table_name = insert.table.clone();
+                let assumption = Interaction::Assumption(Assertion::new(
+                    format!("table {table_name} exists"),
+                    Box::new(move |_: &Vec<ResultSet>, env: &SimulatorEnv| {
+                        Ok(env.tables.iter().any(|t| t.name == table_name))
+                    }),
+                ));
+
+                let rename = Interaction::Query(Query::AlterRenameTable(AlterRenameTable {
+                    table: insert.table.clone(),
+                    new_name: new_name.clone(),
+                }));
+
+                let select = Interaction::Query(Query::Select(Select {
+                    table: new_name.clone(),
+                    predicate: Predicate::True,
+                }));
+
+                let rows = insert.values.clone();
+                let assertion = Interaction::Assertion(
+                    Assertion::new(
+                        format!(
+                            "rows inserted into {} did not survive the rename to {new_name}",
+                            insert.table
+                        ),
+                        Box::new({
+                            let rows = rows.clone();
+                            move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                                let after = stack
+                                    .last()
+                                    .unwrap()
+                                    .as_ref()
+                                    .map_err(|e| LimboError::InternalError(e.to_string()))?;
+                                Ok(rows.iter().all(|row| after.contains(row)))
+                            }
+                        }),
+                    )
+                    .with_diagnose(Box::new(move |stack: &Vec<ResultSet>, _: &SimulatorEnv| {
+                        let actual = match stack.last().unwrap() {
+                            Ok(rows) => format!("{rows:?}"),
+                            Err(e) => format!("error: {e}"),
+                        };
+                        (format!("{rows:?} all present"), actual)
+                    })),
+                );
+
+                vec![
+                    assumption,
+                    Interaction::Query(Query::Insert(insert.clone())),
+                    rename,
+                    select,
+                    assertion,
+                ]
+            }
+        }
+    }
+
+    /// Describe each interaction `interactions()` would produce for this
+    /// property, in the same order, so a `VerifyFailure` report can say e.g.
+    /// "assertion #4 of Insert-Select failed" instead of just an index. Kept
+    /// as a separate pass rather than zipped into `interactions()` itself so
+    /// that plain string labels never need to flow through the executor.
+    pub(crate) fn interaction_roles(&self) -> Vec<String> {
+        let mut roles = Vec::new();
+        match self {
+            Property::InsertSelect { queries, .. } => {
+                roles.push("assumption: table exists".to_string());
+                roles.push("insert".to_string());
+                roles.extend((0..queries.len()).map(|i| format!("middle query {i}")));
+                roles.push("select".to_string());
+                roles.push("assertion: inserted row present".to_string());
+            }
+            Property::DoubleCreateFailure { queries, .. } => {
+                roles.push("assumption: table does not already exist".to_string());
+                roles.push("create".to_string());
+                roles.extend((0..queries.len()).map(|i| format!("middle query {i}")));
+                roles.push("create (expected to fail)".to_string());
+                roles.push("assertion: second create failed".to_string());
+            }
+            Property::UncommittedRollback {
+                surviving_insert,
+                queries,
+                nested,
+                ..
+            } => {
+                roles.push("assumption: table exists".to_string());
+                roles.push("snapshot select".to_string());
+                roles.push("begin".to_string());
+                if surviving_insert.is_some() {
+                    roles.push("surviving insert".to_string());
+                }
+                if *nested {
+                    roles.push("savepoint".to_string());
+                }
+                roles.extend((0..queries.len()).map(|i| format!("rolled-back query {i}")));
+                if *nested {
+                    roles.push("rollback to savepoint".to_string());
+                    roles.push("release savepoint".to_string());
+                } else {
+                    roles.push("rollback".to_string());
+                }
+                roles.push("post-rollback select".to_string());
+                roles.push(if *nested {
+                    "assertion: table matches pre-transaction contents plus surviving insert"
+                        .to_string()
+                } else {
+                    "assertion: table matches pre-transaction contents".to_string()
+                });
+            }
+            Property::SelectMatchesModel { .. } => {
+                roles.push("assumption: table exists and is not poisoned".to_string());
+                roles.push("select".to_string());
+                roles.push("assertion: select matches shadow oracle".to_string());
+            }
+            Property::UpdateSelect { .. } => {
+                roles.push("assumption: table exists".to_string());
+                roles.push("insert".to_string());
+                roles.push("update".to_string());
+                roles.push("select".to_string());
+                roles.push("assertion: updated row present, old row absent".to_string());
+            }
+            Property::DropEnsuresAbsence { queries, .. } => {
+                roles.push("assumption: table exists".to_string());
+                roles.push("drop".to_string());
+                roles.extend((0..queries.len()).map(|i| format!("middle query {i}")));
+                roles.push("select (expected to fail)".to_string());
+                roles.push("assertion: select errors with no such table".to_string());
+            }
+            Property::RenameThenSelect { .. } => {
+                roles.push("assumption: table exists".to_string());
+                roles.push("insert".to_string());
+                roles.push("rename".to_string());
+                roles.push("select".to_string());
+                roles.push("assertion: inserted rows survived the rename".to_string());
+            }
         }
+        roles
     }
 }
 
@@ -172,6 +760,7 @@ pub(crate) struct Remaining {
     pub(crate) read: f64,
     pub(crate) write: f64,
     pub(crate) create: f64,
+    pub(crate) transaction: f64,
 }
 
 pub(crate) fn remaining(env: &SimulatorEnv, stats: &InteractionStats) -> Remaining {
@@ -184,11 +773,17 @@ pub(crate) fn remaining(env: &SimulatorEnv, stats: &InteractionStats) -> Remaini
     let remaining_create = ((env.opts.max_interactions as f64 * env.opts.create_percent / 100.0)
         - (stats.create_count as f64))
         .max(0.0);
+    let remaining_transaction = ((env.opts.max_interactions as f64
+        * env.opts.transaction_percent
+        / 100.0)
+        - (stats.transaction_count as f64))
+        .max(0.0);
 
     Remaining {
         read: remaining_read,
         write: remaining_write,
         create: remaining_create,
+        transaction: remaining_transaction,
     }
 }
 
@@ -196,6 +791,7 @@ fn property_insert_select<R: rand::Rng>(
     rng: &mut R,
     env: &SimulatorEnv,
     remaining: &Remaining,
+    stats: &InteractionStats,
 ) -> Property {
     // Get a random table
     let table = pick(&env.tables, rng);
@@ -218,10 +814,10 @@ fn property_insert_select<R: rand::Rng>(
     let mut queries = Vec::new();
     // - [x] There will be no errors in the middle interactions. (this constraint is impossible to check, so this is just best effort)
     // - [x] The inserted row will not be deleted.
-    // - [ ] The inserted row will not be updated. (todo: add this constraint once UPDATE is implemented)
-    // - [ ] The table `t` will not be renamed, dropped, or altered. (todo: add this constraint once ALTER or DROP is implemented)
+    // - [x] The inserted row will not be updated.
+    // - [x] The table `t` will not be renamed, dropped, or altered.
     for _ in 0..rng.gen_range(0..3) {
-        let query = Query::arbitrary_from(rng, (table, remaining));
+        let query = Query::arbitrary_from(rng, (table, remaining, stats, env.opts.generation_strategy));
         match &query {
             Query::Delete(Delete {
                 table: t,
@@ -232,6 +828,16 @@ fn property_insert_select<R: rand::Rng>(
                     continue;
                 }
             }
+            Query::Update(Update {
+                table: t,
+                predicate,
+                ..
+            }) => {
+                // The inserted row will not be updated.
+                if t == &table.name && predicate.test(&row, table) {
+                    continue;
+                }
+            }
             Query::Create(Create { table: t }) => {
                 // There will be no errors in the middle interactions.
                 // - Creating the same table is an error
@@ -239,6 +845,17 @@ fn property_insert_select<R: rand::Rng>(
                     continue;
                 }
             }
+            Query::Drop(Drop { table: t })
+            | Query::AlterRenameTable(AlterRenameTable { table: t, .. })
+            | Query::AlterAddColumn(AlterAddColumn { table: t, .. }) => {
+                // The table `t` will not be renamed, dropped, or altered: an
+                // AlterAddColumn on `t` would desync `row`/`insert` from the
+                // real schema (they're positional, so the new column would
+                // show up as an extra trailing NULL in the final SELECT).
+                if t == &table.name {
+                    continue;
+                }
+            }
             _ => (),
         }
         queries.push(query);
@@ -262,6 +879,7 @@ fn property_double_create_failure<R: rand::Rng>(
     rng: &mut R,
     env: &SimulatorEnv,
     remaining: &Remaining,
+    stats: &InteractionStats,
 ) -> Property {
     // Get a random table
     let table = pick(&env.tables, rng);
@@ -274,9 +892,9 @@ fn property_double_create_failure<R: rand::Rng>(
     let mut queries = Vec::new();
     // The interactions in the middle has the following constraints;
     // - [x] There will be no errors in the middle interactions.(best effort)
-    // - [ ] Table `t` will not be renamed or dropped.(todo: add this constraint once ALTER or DROP is implemented)
+    // - [x] Table `t` will not be renamed or dropped.
     for _ in 0..rng.gen_range(0..3) {
-        let query = Query::arbitrary_from(rng, (table, remaining));
+        let query = Query::arbitrary_from(rng, (table, remaining, stats, env.opts.generation_strategy));
         match &query {
             Query::Create(Create { table: t }) => {
                 // There will be no errors in the middle interactions.
@@ -285,6 +903,16 @@ fn property_double_create_failure<R: rand::Rng>(
                     continue;
                 }
             }
+            Query::Drop(Drop { table: t }) | Query::AlterRenameTable(AlterRenameTable { table: t, .. }) => {
+                // Table `t` will not be renamed or dropped.
+                if t == &table.name {
+                    continue;
+                }
+            }
+            // Updates don't conflict with this property: it only tracks the
+            // table's existence, not any row, so an Update is always safe to
+            // include in the middle of the plan.
+            Query::Update(_) => (),
             _ => (),
         }
         queries.push(query);
@@ -296,21 +924,260 @@ fn property_double_create_failure<R: rand::Rng>(
     }
 }
 
+/// Generate the batch of writes that happens inside the scope which is going
+/// to be rolled back. Schema changes (drop, rename, add column) are left out,
+/// since they would make later queries in the batch error; whatever the
+/// remaining writes do, rolling the scope back must undo it.
+fn arbitrary_rollback_batch<R: rand::Rng>(
+    rng: &mut R,
+    table: &crate::model::table::Table,
+    remaining: &Remaining,
+    stats: &InteractionStats,
+    strategy: GenerationStrategy,
+) -> Vec<Query> {
+    (0..rng.gen_range(1..=3))
+        .map(|_| Query::arbitrary_from(rng, (table, remaining, stats, strategy)))
+        .filter(|q| {
+            !matches!(
+                q,
+                Query::Drop(_) | Query::AlterRenameTable(_) | Query::AlterAddColumn(_)
+            )
+        })
+        .collect()
+}
+
+fn property_uncommitted_rollback<R: rand::Rng>(
+    rng: &mut R,
+    env: &SimulatorEnv,
+    remaining: &Remaining,
+    stats: &InteractionStats,
+) -> Property {
+    let table = pick(&env.tables, rng);
+    let queries = arbitrary_rollback_batch(rng, table, remaining, stats, env.opts.generation_strategy);
+
+    Property::UncommittedRollback {
+        table: table.name.clone(),
+        surviving_insert: None,
+        queries,
+        nested: false,
+    }
+}
+
+fn property_nested_savepoint_rollback<R: rand::Rng>(
+    rng: &mut R,
+    env: &SimulatorEnv,
+    remaining: &Remaining,
+    stats: &InteractionStats,
+) -> Property {
+    let table = pick(&env.tables, rng);
+
+    let surviving_rows = (0..rng.gen_range(1..=3))
+        .map(|_| Vec::<Value>::arbitrary_from(rng, table))
+        .collect::<Vec<_>>();
+    let surviving_insert = Insert {
+        table: table.name.clone(),
+        values: surviving_rows,
+    };
+
+    let queries = arbitrary_rollback_batch(rng, table, remaining, stats, env.opts.generation_strategy);
+
+    Property::UncommittedRollback {
+        table: table.name.clone(),
+        surviving_insert: Some(surviving_insert),
+        queries,
+        nested: true,
+    }
+}
+
+fn property_select_matches_model<R: rand::Rng>(rng: &mut R, env: &SimulatorEnv) -> Property {
+    let table = pick(&env.tables, rng);
+
+    // Bias towards a predicate that actually matches some row when the
+    // oracle has any, so the property isn't trivially vacuous most of the
+    // time; fall back to an unfiltered select otherwise.
+    let predicate = match env.oracle.get(&table.name) {
+        Some(oracle) if !oracle.poisoned && !oracle.rows.is_empty() => {
+            let row = pick(&oracle.rows, rng);
+            Predicate::arbitrary_from(rng, (table, row))
+        }
+        _ => Predicate::True,
+    };
+
+    Property::SelectMatchesModel {
+        table: table.name.clone(),
+        predicate,
+    }
+}
+
+fn property_update_select<R: rand::Rng>(rng: &mut R, env: &SimulatorEnv) -> Property {
+    // Get a random table
+    let table = pick(&env.tables, rng);
+    // Generate rows to insert
+    let rows = (0..rng.gen_range(1..=5))
+        .map(|_| Vec::<Value>::arbitrary_from(rng, table))
+        .collect::<Vec<_>>();
+
+    // Pick a random row to update
+    let row_index = pick_index(rows.len(), rng);
+    let row = rows[row_index].clone();
+
+    let insert_query = Insert {
+        table: table.name.clone(),
+        values: rows,
+    };
+
+    // Reject no-op updates: if `updated_row == row`, the assertion's "old row
+    // is gone" half can never hold even when the update behaved correctly.
+    let (update_query, updated_row) = loop {
+        let update_query = Update::arbitrary_from(rng, (table, &row));
+
+        let mut updated_row = row.clone();
+        for assignment in &update_query.assignments {
+            if let Some(idx) = table.columns.iter().position(|c| c.name == assignment.column) {
+                updated_row[idx] = assignment.value.clone();
+            }
+        }
+
+        if updated_row != row {
+            break (update_query, updated_row);
+        }
+    };
+
+    // Select the whole table, so the assertion can check both that the
+    // updated row showed up and that the old row is gone.
+    let select_query = Select {
+        table: table.name.clone(),
+        predicate: Predicate::True,
+    };
+
+    Property::UpdateSelect {
+        insert: insert_query,
+        row_index,
+        update: update_query,
+        updated_row,
+        select: select_query,
+    }
+}
+
+fn property_drop_ensures_absence<R: rand::Rng>(
+    rng: &mut R,
+    env: &SimulatorEnv,
+    remaining: &Remaining,
+    stats: &InteractionStats,
+) -> Property {
+    let table = pick(&env.tables, rng);
+
+    // The middle interactions must not error, so they can't target `table`
+    // itself (it no longer exists once DROP has run). Generate them against
+    // another surviving table instead; if there is none, leave the middle
+    // empty rather than violate that invariant.
+    let other_tables = env
+        .tables
+        .iter()
+        .filter(|t| t.name != table.name)
+        .collect::<Vec<_>>();
+    let mut queries = Vec::new();
+    if !other_tables.is_empty() {
+        for _ in 0..rng.gen_range(0..3) {
+            let middle_table = pick(&other_tables, rng);
+            let query = Query::arbitrary_from(
+                rng,
+                (*middle_table, remaining, stats, env.opts.generation_strategy),
+            );
+            // `other_tables` is snapshotted once above, so a Drop or
+            // AlterRenameTable here could take one of them out from under a
+            // later middle query targeting the same table, and an
+            // AlterAddColumn would change its column count under a later
+            // insert. Skip DDL instead.
+            match &query {
+                Query::Drop(_) | Query::AlterRenameTable(_) | Query::AlterAddColumn(_) => {
+                    continue
+                }
+                _ => {}
+            }
+            queries.push(query);
+        }
+    }
+
+    Property::DropEnsuresAbsence {
+        table: table.name.clone(),
+        queries,
+    }
+}
+
+fn property_rename_then_select<R: rand::Rng>(rng: &mut R, env: &SimulatorEnv) -> Property {
+    let table = pick(&env.tables, rng);
+
+    let rows = (0..rng.gen_range(1..=5))
+        .map(|_| Vec::<Value>::arbitrary_from(rng, table))
+        .collect::<Vec<_>>();
+    let insert_query = Insert {
+        table: table.name.clone(),
+        values: rows,
+    };
+
+    Property::RenameThenSelect {
+        insert: insert_query,
+        new_name: format!("{}_renamed", table.name),
+    }
+}
+
 impl ArbitraryFrom<(&SimulatorEnv, &InteractionStats)> for Property {
     fn arbitrary_from<R: rand::Rng>(
         rng: &mut R,
         (env, stats): (&SimulatorEnv, &InteractionStats),
     ) -> Self {
         let remaining_ = remaining(env, stats);
+        // Pluggable weighting: `UniformByBudget` keeps this exactly the
+        // original behaviour; `CoverageGuided` additionally folds in
+        // `coverage_factor` so properties that have been exercised least, or
+        // that have historically surfaced discrepancies, get drawn more
+        // often than ones that mostly skip via their own assumption.
+        let weight = |base: f64, name: &str| match env.opts.generation_strategy {
+            GenerationStrategy::UniformByBudget => base,
+            GenerationStrategy::CoverageGuided => base * coverage_factor(stats, name),
+        };
         frequency(
             vec![
                 (
-                    f64::min(remaining_.read, remaining_.write),
-                    Box::new(|rng: &mut R| property_insert_select(rng, env, &remaining_)),
+                    weight(f64::min(remaining_.read, remaining_.write), "Insert-Select"),
+                    Box::new(|rng: &mut R| property_insert_select(rng, env, &remaining_, stats)),
+                ),
+                (
+                    weight(remaining_.create / 2.0, "Double-Create-Failure"),
+                    Box::new(|rng: &mut R| {
+                        property_double_create_failure(rng, env, &remaining_, stats)
+                    }),
+                ),
+                (
+                    weight(remaining_.transaction / 2.0, "Uncommitted-Rollback"),
+                    Box::new(|rng: &mut R| {
+                        property_uncommitted_rollback(rng, env, &remaining_, stats)
+                    }),
+                ),
+                (
+                    weight(remaining_.transaction / 2.0, "Nested-Savepoint-Rollback"),
+                    Box::new(|rng: &mut R| {
+                        property_nested_savepoint_rollback(rng, env, &remaining_, stats)
+                    }),
+                ),
+                (
+                    weight(remaining_.read, "Select-Matches-Model"),
+                    Box::new(|rng: &mut R| property_select_matches_model(rng, env)),
+                ),
+                (
+                    weight(f64::min(remaining_.read, remaining_.write), "Update-Select"),
+                    Box::new(|rng: &mut R| property_update_select(rng, env)),
+                ),
+                (
+                    weight(remaining_.create / 2.0, "Drop-Ensures-Absence"),
+                    Box::new(|rng: &mut R| {
+                        property_drop_ensures_absence(rng, env, &remaining_, stats)
+                    }),
                 ),
                 (
-                    remaining_.create / 2.0,
-                    Box::new(|rng: &mut R| property_double_create_failure(rng, env, &remaining_)),
+                    weight(remaining_.create / 2.0, "Rename-Then-Select"),
+                    Box::new(|rng: &mut R| property_rename_then_select(rng, env)),
                 ),
             ],
             rng,
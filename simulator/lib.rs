@@ -0,0 +1,3 @@
+pub(crate) mod generation;
+pub(crate) mod model;
+pub(crate) mod runner;
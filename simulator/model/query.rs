@@ -0,0 +1,375 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::table::{Column, ColumnType, Table, Value};
+use crate::generation::{
+    frequency,
+    plan::{coverage_factor, InteractionStats},
+    pick, ArbitraryFrom, GenerationStrategy,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Create {
+    pub(crate) table: Table,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Drop {
+    pub(crate) table: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct AlterRenameTable {
+    pub(crate) table: String,
+    pub(crate) new_name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct AlterAddColumn {
+    pub(crate) table: String,
+    pub(crate) column: Column,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Insert {
+    pub(crate) table: String,
+    pub(crate) values: Vec<Vec<Value>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Delete {
+    pub(crate) table: String,
+    pub(crate) predicate: Predicate,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Select {
+    pub(crate) table: String,
+    pub(crate) predicate: Predicate,
+}
+
+/// A `col = value`-shaped predicate, ANDed together, used both to generate
+/// `WHERE` clauses and to evaluate them in-memory against generated rows.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) enum Predicate {
+    /// Matches every row, i.e. no `WHERE` clause.
+    True,
+    /// `column = value`
+    Eq(String, Value),
+    /// The conjunction of several predicates.
+    And(Vec<Predicate>),
+}
+
+impl Predicate {
+    /// Evaluate the predicate against a row, using `table` to resolve column
+    /// names to indices.
+    ///
+    /// Note this is SQL `=` comparison, not row-identity comparison: per
+    /// SQLite's three-valued logic, `NULL = NULL` is unknown, so a `NULL`
+    /// column value never satisfies an `Eq` predicate, even against another
+    /// `NULL`. Don't reuse this for "are these two rows the same row"
+    /// checks (e.g. oracle vs. real result set), where two `NULL`s should be
+    /// treated as equal; use `Value`'s own `PartialEq` for that instead.
+    pub(crate) fn test(&self, row: &[Value], table: &Table) -> bool {
+        match self {
+            Predicate::True => true,
+            Predicate::Eq(column, value) => table
+                .columns
+                .iter()
+                .position(|c| &c.name == column)
+                .map(|idx| match (&row[idx], value) {
+                    (Value::Null, _) | (_, Value::Null) => false,
+                    (actual, expected) => actual == expected,
+                })
+                .unwrap_or(false),
+            Predicate::And(predicates) => predicates.iter().all(|p| p.test(row, table)),
+        }
+    }
+}
+
+impl ArbitraryFrom<(&Table, &Vec<Value>)> for Predicate {
+    fn arbitrary_from<R: rand::Rng>(rng: &mut R, (table, row): (&Table, &Vec<Value>)) -> Self {
+        let column = pick(&table.columns, rng);
+        let idx = table
+            .columns
+            .iter()
+            .position(|c| c.name == column.name)
+            .unwrap();
+        Predicate::Eq(column.name.clone(), row[idx].clone())
+    }
+}
+
+/// A single `SET column = value` assignment within an `UPDATE` statement.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Assignment {
+    pub(crate) column: String,
+    pub(crate) value: Value,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Update {
+    pub(crate) table: String,
+    pub(crate) assignments: Vec<Assignment>,
+    pub(crate) predicate: Predicate,
+}
+
+impl ArbitraryFrom<(&Table, &Vec<Value>)> for Update {
+    /// Build an `UPDATE` that targets `row` (via a predicate matching it) and
+    /// assigns one or more of `table`'s columns to freshly generated values.
+    fn arbitrary_from<R: rand::Rng>(rng: &mut R, (table, row): (&Table, &Vec<Value>)) -> Self {
+        let predicate = Predicate::arbitrary_from(rng, (table, row));
+
+        let num_assignments = rng.gen_range(1..=table.columns.len());
+        // Pick a random subset of columns, not just the leading ones, so
+        // updates to later columns get exercised too.
+        let mut column_indices: Vec<usize> = (0..table.columns.len()).collect();
+        for i in (1..column_indices.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            column_indices.swap(i, j);
+        }
+        column_indices.truncate(num_assignments);
+        let assignments = column_indices
+            .into_iter()
+            .map(|i| {
+                let c = &table.columns[i];
+                Assignment {
+                    column: c.name.clone(),
+                    value: Value::arbitrary_from(rng, &c.column_type),
+                }
+            })
+            .collect();
+
+        Update {
+            table: table.name.clone(),
+            assignments,
+            predicate,
+        }
+    }
+}
+
+/// Every query the simulator knows how to generate and execute. Interactions
+/// are built out of these, and each one also knows how to render itself back
+/// into the SQL text that is actually sent to limbo_core.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) enum Query {
+    Create(Create),
+    Select(Select),
+    Insert(Insert),
+    Delete(Delete),
+    Update(Update),
+    /// `DROP TABLE <table>`
+    Drop(Drop),
+    /// `ALTER TABLE <table> RENAME TO <new_name>`
+    AlterRenameTable(AlterRenameTable),
+    /// `ALTER TABLE <table> ADD COLUMN <column>`
+    AlterAddColumn(AlterAddColumn),
+    /// `BEGIN`
+    Begin,
+    /// `COMMIT`
+    Commit,
+    /// `ROLLBACK`
+    Rollback,
+    /// `SAVEPOINT <name>`
+    Savepoint(String),
+    /// `RELEASE <name>`
+    ReleaseSavepoint(String),
+    /// `ROLLBACK TO <name>`
+    RollbackTo(String),
+}
+
+impl Query {
+    /// The table this query reads or writes, if any. Used to decide which
+    /// shadow oracle entry to poison when the query fails.
+    pub(crate) fn touched_table(&self) -> Option<&str> {
+        match self {
+            Query::Create(Create { table }) => Some(&table.name),
+            Query::Select(Select { table, .. })
+            | Query::Insert(Insert { table, .. })
+            | Query::Delete(Delete { table, .. })
+            | Query::Update(Update { table, .. })
+            | Query::Drop(Drop { table })
+            | Query::AlterRenameTable(AlterRenameTable { table, .. })
+            | Query::AlterAddColumn(AlterAddColumn { table, .. }) => Some(table),
+            Query::Begin
+            | Query::Commit
+            | Query::Rollback
+            | Query::Savepoint(_)
+            | Query::ReleaseSavepoint(_)
+            | Query::RollbackTo(_) => None,
+        }
+    }
+}
+
+impl Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Query::Create(Create { table }) => {
+                write!(
+                    f,
+                    "CREATE TABLE {} ({})",
+                    table.name,
+                    table
+                        .columns
+                        .iter()
+                        .map(|c| format!("{} {}", c.name, c.column_type))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Query::Select(Select { table, predicate }) => {
+                write!(f, "SELECT * FROM {table} WHERE {predicate}")
+            }
+            Query::Insert(Insert { table, values }) => {
+                write!(
+                    f,
+                    "INSERT INTO {table} VALUES {}",
+                    values
+                        .iter()
+                        .map(|row| format!(
+                            "({})",
+                            row.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ")
+                        ))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Query::Delete(Delete { table, predicate }) => {
+                write!(f, "DELETE FROM {table} WHERE {predicate}")
+            }
+            Query::Update(Update {
+                table,
+                assignments,
+                predicate,
+            }) => {
+                write!(
+                    f,
+                    "UPDATE {table} SET {} WHERE {predicate}",
+                    assignments
+                        .iter()
+                        .map(|a| format!("{} = {}", a.column, a.value))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Query::Drop(Drop { table }) => write!(f, "DROP TABLE {table}"),
+            Query::AlterRenameTable(AlterRenameTable { table, new_name }) => {
+                write!(f, "ALTER TABLE {table} RENAME TO {new_name}")
+            }
+            Query::AlterAddColumn(AlterAddColumn { table, column }) => {
+                write!(f, "ALTER TABLE {table} ADD COLUMN {} {}", column.name, column.column_type)
+            }
+            Query::Begin => write!(f, "BEGIN"),
+            Query::Commit => write!(f, "COMMIT"),
+            Query::Rollback => write!(f, "ROLLBACK"),
+            Query::Savepoint(name) => write!(f, "SAVEPOINT {name}"),
+            Query::ReleaseSavepoint(name) => write!(f, "RELEASE {name}"),
+            Query::RollbackTo(name) => write!(f, "ROLLBACK TO {name}"),
+        }
+    }
+}
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Predicate::True => write!(f, "TRUE"),
+            Predicate::Eq(column, value) => write!(f, "{column} = {value}"),
+            Predicate::And(predicates) => write!(
+                f,
+                "{}",
+                predicates
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" AND ")
+            ),
+        }
+    }
+}
+
+/// Context threaded into `Query` generation: the table being mutated, the
+/// remaining read/write budget, and the coverage stats + strategy used to
+/// decide whether to also bias by `plan::coverage_factor`.
+pub(crate) type QueryGenContext<'a> = (
+    &'a Table,
+    &'a crate::generation::property::Remaining,
+    &'a InteractionStats,
+    GenerationStrategy,
+);
+
+impl ArbitraryFrom<QueryGenContext<'_>> for Query {
+    // TODO: also occasionally generate `Create` for an unrelated table once
+    // arbitrary schema generation exists.
+    fn arbitrary_from<R: rand::Rng>(
+        rng: &mut R,
+        (table, remaining, stats, strategy): QueryGenContext<'_>,
+    ) -> Self {
+        let weight = |base: f64, shape: &str| match strategy {
+            GenerationStrategy::UniformByBudget => base,
+            GenerationStrategy::CoverageGuided => base * coverage_factor(stats, shape),
+        };
+        frequency(
+            vec![
+                (
+                    weight(remaining.write.max(1.0), "insert"),
+                    Box::new(|rng: &mut R| {
+                        Query::Insert(Insert {
+                            table: table.name.clone(),
+                            values: vec![Vec::<Value>::arbitrary_from(rng, table)],
+                        })
+                    }) as Box<dyn Fn(&mut R) -> Query>,
+                ),
+                (
+                    weight(remaining.write.max(1.0), "delete"),
+                    Box::new(|rng: &mut R| {
+                        let row = Vec::<Value>::arbitrary_from(rng, table);
+                        Query::Delete(Delete {
+                            table: table.name.clone(),
+                            predicate: Predicate::arbitrary_from(rng, (table, &row)),
+                        })
+                    }),
+                ),
+                (
+                    weight(remaining.write.max(1.0), "update"),
+                    Box::new(|rng: &mut R| {
+                        let row = Vec::<Value>::arbitrary_from(rng, table);
+                        Query::Update(Update::arbitrary_from(rng, (table, &row)))
+                    }),
+                ),
+                // DDL is generated rarely; properties that depend on `table`
+                // surviving the middle of their plan are responsible for
+                // filtering these back out.
+                (
+                    weight(remaining.write.max(1.0) * 0.1, "drop"),
+                    Box::new(|_rng: &mut R| {
+                        Query::Drop(Drop {
+                            table: table.name.clone(),
+                        })
+                    }),
+                ),
+                (
+                    weight(remaining.write.max(1.0) * 0.1, "alter_rename_table"),
+                    Box::new(|_rng: &mut R| {
+                        Query::AlterRenameTable(AlterRenameTable {
+                            table: table.name.clone(),
+                            new_name: format!("{}_renamed", table.name),
+                        })
+                    }),
+                ),
+                (
+                    weight(remaining.write.max(1.0) * 0.1, "alter_add_column"),
+                    Box::new(|_rng: &mut R| {
+                        Query::AlterAddColumn(AlterAddColumn {
+                            table: table.name.clone(),
+                            column: Column {
+                                name: format!("col{}", table.columns.len()),
+                                column_type: ColumnType::Integer,
+                            },
+                        })
+                    }),
+                ),
+            ],
+            rng,
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::generation::ArbitraryFrom;
+
+/// A column's declared SQLite storage class.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) enum ColumnType {
+    Integer,
+    Float,
+    Text,
+    Blob,
+}
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Integer => write!(f, "INTEGER"),
+            ColumnType::Float => write!(f, "REAL"),
+            ColumnType::Text => write!(f, "TEXT"),
+            ColumnType::Blob => write!(f, "BLOB"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Column {
+    pub(crate) name: String,
+    pub(crate) column_type: ColumnType,
+}
+
+/// A table's schema, as tracked by the simulator. This mirrors what the
+/// generator needs to know in order to produce well-formed queries against
+/// the table; it does not track row contents (see `TableOracle` for that).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct Table {
+    pub(crate) name: String,
+    pub(crate) columns: Vec<Column>,
+}
+
+/// A single SQLite value, as produced by the generator or returned by a
+/// query result row.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Value {
+    Null,
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Integer(i) => write!(f, "{i}"),
+            Value::Float(fl) => write!(f, "{fl}"),
+            Value::Text(t) => write!(f, "'{t}'"),
+            Value::Blob(b) => write!(f, "X'{}'", b.iter().map(|x| format!("{x:02x}")).collect::<String>()),
+        }
+    }
+}
+
+impl ArbitraryFrom<&ColumnType> for Value {
+    fn arbitrary_from<R: rand::Rng>(rng: &mut R, column_type: &ColumnType) -> Self {
+        match column_type {
+            ColumnType::Integer => Value::Integer(rng.gen_range(-1000..1000)),
+            ColumnType::Float => Value::Float(rng.gen_range(-1000.0..1000.0)),
+            ColumnType::Text => {
+                let len = rng.gen_range(1..10);
+                Value::Text((0..len).map(|_| rng.gen_range(b'a'..=b'z') as char).collect())
+            }
+            ColumnType::Blob => {
+                let len = rng.gen_range(1..10);
+                Value::Blob((0..len).map(|_| rng.gen()).collect())
+            }
+        }
+    }
+}
+
+/// Generate a row matching `table`'s column types, in column order.
+impl ArbitraryFrom<&Table> for Vec<Value> {
+    fn arbitrary_from<R: rand::Rng>(rng: &mut R, table: &Table) -> Self {
+        table
+            .columns
+            .iter()
+            .map(|c| Value::arbitrary_from(rng, &c.column_type))
+            .collect()
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use crate::{
+    generation::GenerationStrategy,
+    model::{
+        query::{AlterAddColumn, AlterRenameTable, Create, Delete, Drop, Insert, Query, Update},
+        table::{Table, Value},
+    },
+};
+
+/// Knobs controlling how much of each interaction "shape" the generator
+/// should produce over the course of a run.
+pub(crate) struct SimulatorOpts {
+    pub(crate) max_interactions: usize,
+    pub(crate) read_percent: f64,
+    pub(crate) write_percent: f64,
+    pub(crate) create_percent: f64,
+    /// Share of `max_interactions` that should be spent on transaction /
+    /// savepoint atomicity properties.
+    pub(crate) transaction_percent: f64,
+    /// Whether `Property` and `Query` generation weight purely by remaining
+    /// budget, or also fold in coverage/cost feedback from `InteractionStats`.
+    pub(crate) generation_strategy: GenerationStrategy,
+}
+
+/// The in-memory shadow of a single table's contents, maintained alongside
+/// the real database so that `Property::SelectMatchesModel` has something to
+/// check a `SELECT` against without re-deriving it from the interaction
+/// history every time.
+#[derive(Default, Clone)]
+pub(crate) struct TableOracle {
+    pub(crate) rows: Vec<Vec<Value>>,
+    /// Set when a query touching this table errored, or after any rollback
+    /// (the oracle keeps no per-transaction snapshot), meaning we no longer
+    /// know its true contents. Properties must skip asserting
+    /// against a poisoned table rather than risk a false positive.
+    pub(crate) poisoned: bool,
+}
+
+/// The simulator's view of the world: the schema it believes exists, the
+/// options driving generation, and a row-level shadow oracle of each table's
+/// contents: what the generator expects to be true of a limbo_core
+/// connection that the interactions run against. No code in this crate
+/// executes interactions or updates this struct from their results.
+pub(crate) struct SimulatorEnv {
+    pub(crate) tables: Vec<Table>,
+    pub(crate) opts: SimulatorOpts,
+    pub(crate) oracle: HashMap<String, TableOracle>,
+}
+
+impl SimulatorEnv {
+    /// Apply a query that limbo_core executed successfully to the shadow
+    /// oracle. Nothing in this crate calls it.
+    pub(crate) fn record_success(&mut self, query: &Query) {
+        match query {
+            Query::Create(Create { table }) => {
+                self.tables.push(table.clone());
+                self.oracle
+                    .insert(table.name.clone(), TableOracle::default());
+            }
+            Query::Insert(Insert { table, values }) => {
+                if let Some(oracle) = self.oracle.get_mut(table) {
+                    if !oracle.poisoned {
+                        oracle.rows.extend(values.clone());
+                    }
+                }
+            }
+            Query::Delete(Delete { table, predicate }) => {
+                if let Some(schema) = self.tables.iter().find(|t| &t.name == table) {
+                    let predicate = predicate.clone();
+                    let schema = schema.clone();
+                    if let Some(oracle) = self.oracle.get_mut(table) {
+                        if !oracle.poisoned {
+                            oracle.rows.retain(|row| !predicate.test(row, &schema));
+                        }
+                    }
+                }
+            }
+            Query::Update(Update {
+                table,
+                assignments,
+                predicate,
+            }) => {
+                if let Some(schema) = self.tables.iter().find(|t| &t.name == table) {
+                    let schema = schema.clone();
+                    if let Some(oracle) = self.oracle.get_mut(table) {
+                        if !oracle.poisoned {
+                            for row in oracle.rows.iter_mut().filter(|row| predicate.test(row, &schema)) {
+                                for assignment in assignments {
+                                    if let Some(idx) =
+                                        schema.columns.iter().position(|c| c.name == assignment.column)
+                                    {
+                                        row[idx] = assignment.value.clone();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            Query::Drop(Drop { table }) => {
+                self.tables.retain(|t| &t.name != table);
+                self.oracle.remove(table);
+            }
+            Query::AlterRenameTable(AlterRenameTable { table, new_name }) => {
+                if let Some(t) = self.tables.iter_mut().find(|t| &t.name == table) {
+                    t.name = new_name.clone();
+                }
+                if let Some(oracle) = self.oracle.remove(table) {
+                    self.oracle.insert(new_name.clone(), oracle);
+                }
+            }
+            Query::AlterAddColumn(AlterAddColumn { table, column }) => {
+                if let Some(t) = self.tables.iter_mut().find(|t| &t.name == table) {
+                    t.columns.push(column.clone());
+                }
+                if let Some(oracle) = self.oracle.get_mut(table) {
+                    for row in oracle.rows.iter_mut() {
+                        row.push(Value::Null);
+                    }
+                }
+            }
+            Query::Rollback | Query::RollbackTo(_) => {
+                for oracle in self.oracle.values_mut() {
+                    oracle.poisoned = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Mark every table a failed query may have touched as poisoned, since we
+    /// no longer know whether the failure was a no-op or a partial write.
+    pub(crate) fn record_failure(&mut self, query: &Query) {
+        if let Some(table) = query.touched_table() {
+            if let Some(oracle) = self.oracle.get_mut(table) {
+                oracle.poisoned = true;
+            }
+        }
+    }
+}